@@ -20,9 +20,22 @@ pub(super) enum ReviewIntention {
     Info,
     Invalid,
     Skip,
+    Undo,
     Quit,
 }
 
+/// The outcome of a decided package review, without the [`Package`] itself.
+///
+/// Kept separate from [`ReviewAction`] so the review cursor can hold it
+/// alongside the pending package without taking ownership of the package
+/// before the user's decision is final.
+#[derive(Debug)]
+pub(super) enum ReviewOutcome {
+    AsDependency,
+    Delete,
+    AssignGroup(Rc<Group>),
+}
+
 #[derive(Debug)]
 pub(super) struct ReviewsPerBackend {
     items: Vec<(Box<dyn Backend>, Vec<ReviewAction>)>,
@@ -85,11 +98,6 @@ impl IntoIterator for ReviewsPerBackend {
     }
 }
 
-pub(super) enum ContinueWithReview {
-    Yes,
-    No,
-}
-
 fn extract_actions(
     actions: Vec<ReviewAction>,
     to_delete: &mut Vec<Package>,
@@ -0,0 +1,69 @@
+/// Score `candidate` against `query` as a subsequence match: every character
+/// of `query` must appear in `candidate`, in order, but not necessarily
+/// contiguously. Returns `None` if `query` is not a subsequence of
+/// `candidate`.
+///
+/// Higher scores are better matches. Consecutive matched characters and
+/// matches starting at a word boundary (start of string, or after `-`/`_`/
+/// a space) are weighted more heavily, so e.g. querying `"wm"` ranks
+/// `"window-manager"` above `"awesome"`.
+pub(super) fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query = query.to_lowercase();
+    let candidate = candidate.to_lowercase();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+
+    let mut score: i64 = 0;
+    let mut search_from = 0;
+    let mut prev_matched_idx: Option<usize> = None;
+
+    for query_char in query.chars() {
+        let matched_idx = (search_from..candidate_chars.len())
+            .find(|&idx| candidate_chars[idx] == query_char)?;
+
+        score += 1;
+        if prev_matched_idx.is_some_and(|prev| prev + 1 == matched_idx) {
+            score += 5;
+        }
+        if matched_idx == 0 || matches!(candidate_chars[matched_idx - 1], '-' | '_' | ' ') {
+            score += 10;
+        }
+
+        prev_matched_idx = Some(matched_idx);
+        search_from = matched_idx + 1;
+    }
+
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fuzzy_score;
+
+    #[test]
+    fn non_subsequence_does_not_match() {
+        assert_eq!(fuzzy_score("xyz", "window-manager"), None);
+    }
+
+    #[test]
+    fn empty_query_matches_everything_with_zero_score() {
+        assert_eq!(fuzzy_score("", "window-manager"), Some(0));
+    }
+
+    #[test]
+    fn word_boundary_start_outscores_mid_word_match() {
+        let boundary = fuzzy_score("wm", "window-manager").unwrap();
+        let mid_word = fuzzy_score("wm", "awesome").unwrap();
+        assert!(boundary > mid_word);
+    }
+
+    #[test]
+    fn contiguous_run_outscores_scattered_match() {
+        let contiguous = fuzzy_score("de", "development").unwrap();
+        let scattered = fuzzy_score("de", "docker-engine").unwrap();
+        assert!(contiguous > scattered);
+    }
+}
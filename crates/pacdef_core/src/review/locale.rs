@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::env;
+
+/// The label and accelerator key for a single review action, as defined by
+/// the active [`Locale`].
+#[derive(Debug, Clone)]
+pub(super) struct ActionPrompt {
+    pub(super) label: String,
+    pub(super) accelerator: char,
+}
+
+const CATALOGS: &[(&str, &str)] = &[
+    ("en", include_str!("locales/en.properties")),
+    ("de", include_str!("locales/de.properties")),
+];
+
+/// A catalog of translated strings for the review flow.
+///
+/// Locales are plain Java-properties-style `key = value` catalogs (one entry
+/// per line, `#`-prefixed comments allowed) embedded at compile time from
+/// `review/locales/*.properties`. The active locale is selected from
+/// `$LC_MESSAGES`/`$LANG`, falling back to English for any key the selected
+/// locale does not define.
+#[derive(Debug)]
+pub(super) struct Locale {
+    strings: HashMap<String, String>,
+}
+
+impl Locale {
+    /// Load the locale matching the user's environment.
+    pub(super) fn load() -> Self {
+        let lang = env::var("LC_MESSAGES")
+            .or_else(|_| env::var("LANG"))
+            .unwrap_or_default();
+        let code = lang.split(['_', '.']).next().unwrap_or("");
+
+        let mut strings = find_catalog("en").map(parse_catalog).unwrap_or_default();
+
+        if let Some(catalog) = find_catalog(code) {
+            strings.extend(parse_catalog(catalog));
+        }
+
+        Self { strings }
+    }
+
+    fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        self.strings.get(key).map_or(key, String::as_str)
+    }
+
+    /// Look up a standalone message, e.g. `"message-nothing-to-do"`.
+    pub(super) fn message<'a>(&'a self, key: &'a str) -> &'a str {
+        self.get(key)
+    }
+
+    /// Look up the label and accelerator for a review action, e.g.
+    /// `"query-delete"`.
+    pub(super) fn action(&self, key: &str) -> ActionPrompt {
+        let label = self.get(&format!("{key}.label")).to_owned();
+        let accelerator = self
+            .get(&format!("{key}.accelerator"))
+            .chars()
+            .next()
+            .map_or('?', |c| c.to_lowercase().next().unwrap_or(c));
+        ActionPrompt { label, accelerator }
+    }
+}
+
+fn find_catalog(code: &str) -> Option<&'static str> {
+    CATALOGS
+        .iter()
+        .find(|(name, _)| *name == code)
+        .map(|(_, contents)| *contents)
+}
+
+fn parse_catalog(contents: &str) -> HashMap<String, String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim().to_owned(), value.trim().to_owned()))
+        .collect()
+}
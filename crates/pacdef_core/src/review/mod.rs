@@ -1,8 +1,11 @@
 mod datastructures;
+mod fuzzy;
+mod locale;
+mod policy;
 mod strategy;
 mod ui;
 
-use std::io::{stdin, stdout, Write};
+use std::io::{stdin, stdout, IsTerminal, Write};
 use std::rc::Rc;
 
 use anyhow::Result;
@@ -11,37 +14,61 @@ use crate::backend::{Backend, ToDoPerBackend};
 use crate::ui::{get_user_confirmation, read_single_char_from_terminal};
 use crate::{Group, Package};
 
-use self::datastructures::{ContinueWithReview, ReviewAction, ReviewIntention, ReviewsPerBackend};
+use self::datastructures::{ReviewAction, ReviewIntention, ReviewOutcome, ReviewsPerBackend};
+use self::fuzzy::fuzzy_score;
+use self::locale::Locale;
+pub use self::policy::{MappingEntry, ReviewPolicy};
 use self::strategy::Strategy;
 
 pub fn review(
     todo_per_backend: ToDoPerBackend,
     groups: impl IntoIterator<Item = Group>,
+    policy: ReviewPolicy,
 ) -> Result<()> {
-    let mut reviews = ReviewsPerBackend::new();
+    let locale = Locale::load();
     let mut groups: Vec<Rc<Group>> = groups.into_iter().map(Rc::new).collect();
 
     groups.sort_unstable();
 
     if todo_per_backend.nothing_to_do_for_all_backends() {
-        println!("nothing to do");
+        println!("{}", locale.message("message-nothing-to-do"));
         return Ok(());
     }
 
+    let mut backends: Vec<Box<dyn Backend>> = vec![];
+    let mut pending: Vec<(usize, Package)> = vec![];
+
     for (backend, packages) in todo_per_backend.into_iter() {
-        let mut actions = vec![];
-        for package in packages {
-            println!("{}: {package}", backend.get_section());
-            match get_action_for_package(package, &groups, &mut actions, &*backend)? {
-                ContinueWithReview::Yes => continue,
-                ContinueWithReview::No => return Ok(()),
-            }
+        let backend_index = backends.len();
+        pending.extend(packages.into_iter().map(|package| (backend_index, package)));
+        backends.push(backend);
+    }
+
+    let Some(decisions) = review_pending_packages(&pending, &backends, &groups, &locale, &policy)?
+    else {
+        return Ok(());
+    };
+
+    let mut reviews = ReviewsPerBackend::new();
+    let mut actions_per_backend: Vec<Vec<ReviewAction>> = backends.iter().map(|_| vec![]).collect();
+
+    for ((backend_index, package), outcome) in pending.into_iter().zip(decisions) {
+        if let Some(outcome) = outcome {
+            let action = match outcome {
+                ReviewOutcome::AsDependency => ReviewAction::AsDependency(package),
+                ReviewOutcome::Delete => ReviewAction::Delete(package),
+                ReviewOutcome::AssignGroup(group) => ReviewAction::AssignGroup(package, group),
+            };
+            actions_per_backend[backend_index].push(action);
         }
+    }
+
+    for (backend, actions) in backends.into_iter().zip(actions_per_backend) {
         reviews.push((backend, actions));
     }
 
     if reviews.nothing_to_do() {
-        println!("nothing to do");
+        println!("{}", locale.message("message-nothing-to-do"));
         return Ok(());
     }
 
@@ -70,80 +97,207 @@ pub fn review(
     Ok(())
 }
 
-fn get_action_for_package(
-    package: Package,
+/// Decide the [`ReviewOutcome`] for every pending `(backend, package)` pair,
+/// in the same order as `pending`.
+///
+/// When stdin is a terminal, the user is prompted interactively for each
+/// package. Otherwise — e.g. piped input in a script or CI — there is
+/// nothing to prompt, so every package is resolved from `policy` instead.
+///
+/// Returns `None` if the user quits before reaching the end of `pending`.
+fn review_pending_packages(
+    pending: &[(usize, Package)],
+    backends: &[Box<dyn Backend>],
     groups: &[Rc<Group>],
-    reviews: &mut Vec<ReviewAction>,
-    backend: &dyn Backend,
-) -> Result<ContinueWithReview> {
-    loop {
-        match ask_user_action_for_package(backend.supports_as_dependency())? {
+    locale: &Locale,
+    policy: &ReviewPolicy,
+) -> Result<Option<Vec<Option<ReviewOutcome>>>> {
+    if !stdin().is_terminal() {
+        return Ok(Some(
+            pending
+                .iter()
+                .map(|(backend_index, package)| {
+                    println!("{}: {package}", backends[*backend_index].get_section());
+                    resolve_policy_decision(package, groups, policy, locale)
+                })
+                .collect(),
+        ));
+    }
+
+    review_pending_packages_interactively(pending, backends, groups, locale)
+}
+
+/// Resolve a single package's [`ReviewOutcome`] from `policy`, without
+/// prompting. Used by [`review_pending_packages`] for non-interactive runs.
+fn resolve_policy_decision(
+    package: &Package,
+    groups: &[Rc<Group>],
+    policy: &ReviewPolicy,
+    locale: &Locale,
+) -> Option<ReviewOutcome> {
+    match policy {
+        ReviewPolicy::AssumeSkip => None,
+        ReviewPolicy::AssumeDelete => Some(ReviewOutcome::Delete),
+        ReviewPolicy::Mapping { assignments, default } => {
+            match assignments.get(&package.to_string()) {
+                Some(MappingEntry::Delete) => Some(ReviewOutcome::Delete),
+                Some(MappingEntry::Group(name)) => {
+                    let found = groups.iter().find(|group| &group.name == name);
+                    if found.is_none() {
+                        eprintln!("{} {name}", locale.message("message-unknown-group"));
+                    }
+                    found.map(|group| ReviewOutcome::AssignGroup(group.clone()))
+                }
+                None => resolve_policy_decision(package, groups, default, locale),
+            }
+        }
+    }
+}
+
+/// Walk every pending `(backend, package)` pair through a single cursor,
+/// prompting the user for each one and returning the decided
+/// [`ReviewOutcome`] for each (`None` for a skip), in the same order as
+/// `pending`.
+///
+/// Decisions made so far live behind `cursor`, which only ever advances by
+/// one package at a time, so undoing always means stepping back exactly one
+/// package. [`ReviewIntention::Undo`] decrements `cursor`, dropping the
+/// decision it had recorded (releasing any `Rc<Group>` it held), and
+/// re-presents that package. Undoing at the first package is a no-op.
+///
+/// The `"section: package"` header is printed whenever `cursor` moves to a
+/// package (including back to one after an undo), not on every loop
+/// iteration — so re-prompting after [`ReviewIntention::Info`] or
+/// [`ReviewIntention::Invalid`] (neither of which move the cursor) doesn't
+/// reprint it.
+///
+/// Returns `None` if the user quits before reaching the end of `pending`.
+fn review_pending_packages_interactively(
+    pending: &[(usize, Package)],
+    backends: &[Box<dyn Backend>],
+    groups: &[Rc<Group>],
+    locale: &Locale,
+) -> Result<Option<Vec<Option<ReviewOutcome>>>> {
+    let mut decisions: Vec<Option<ReviewOutcome>> = pending.iter().map(|_| None).collect();
+    let mut cursor = 0;
+    let mut last_printed_cursor: Option<usize> = None;
+
+    while cursor < pending.len() {
+        let (backend_index, package) = &pending[cursor];
+        let backend = &*backends[*backend_index];
+
+        if last_printed_cursor != Some(cursor) {
+            println!("{}: {package}", backend.get_section());
+            last_printed_cursor = Some(cursor);
+        }
+
+        match ask_user_action_for_package(backend.supports_as_dependency(), locale)? {
             ReviewIntention::AsDependency => {
                 assert!(
                     backend.supports_as_dependency(),
                     "backend does not support dependencies"
                 );
-                reviews.push(ReviewAction::AsDependency(package));
-                break;
+                decisions[cursor] = Some(ReviewOutcome::AsDependency);
+                cursor += 1;
             }
             ReviewIntention::AssignGroup => {
                 if let Ok(Some(group)) = ask_group(groups) {
-                    reviews.push(ReviewAction::AssignGroup(package, group));
-                    break;
-                };
+                    decisions[cursor] = Some(ReviewOutcome::AssignGroup(group));
+                    cursor += 1;
+                }
             }
             ReviewIntention::Delete => {
-                reviews.push(ReviewAction::Delete(package));
-                break;
+                decisions[cursor] = Some(ReviewOutcome::Delete);
+                cursor += 1;
             }
             ReviewIntention::Info => {
-                backend.show_package_info(&package)?;
+                backend.show_package_info(package)?;
             }
             ReviewIntention::Invalid => (),
-            ReviewIntention::Skip => break,
-            ReviewIntention::Quit => return Ok(ContinueWithReview::No),
+            ReviewIntention::Skip => {
+                cursor += 1;
+            }
+            ReviewIntention::Undo => {
+                if cursor > 0 {
+                    cursor -= 1;
+                    decisions[cursor] = None;
+                } else {
+                    println!("{}", locale.message("message-nothing-to-undo"));
+                }
+            }
+            ReviewIntention::Quit => return Ok(None),
         }
     }
-    Ok(ContinueWithReview::Yes)
+
+    Ok(Some(decisions))
 }
 
 /// Ask the user for the desired action, and return the associated
 /// [`ReviewIntention`]. The query depends on the capabilities of the backend.
 ///
+/// The accelerator chars are defined by `locale`, so this matches against
+/// whatever the active catalog assigns to each action rather than hardcoded
+/// literals, keeping the mnemonics in sync with the translated query text.
+///
 /// # Errors
 ///
 /// This function will return an error if stdin or stdout cannot be accessed.
-fn ask_user_action_for_package(supports_as_dependency: bool) -> Result<ReviewIntention> {
-    print_query(supports_as_dependency)?;
-
-    match read_single_char_from_terminal()?.to_ascii_lowercase() {
-        'a' if supports_as_dependency => Ok(ReviewIntention::AsDependency),
-        'd' => Ok(ReviewIntention::Delete),
-        'g' => Ok(ReviewIntention::AssignGroup),
-        'i' => Ok(ReviewIntention::Info),
-        'q' => Ok(ReviewIntention::Quit),
-        's' => Ok(ReviewIntention::Skip),
-        _ => Ok(ReviewIntention::Invalid),
-    }
+fn ask_user_action_for_package(
+    supports_as_dependency: bool,
+    locale: &Locale,
+) -> Result<ReviewIntention> {
+    print_query(supports_as_dependency, locale)?;
+
+    // Full Unicode case folding, not `to_ascii_lowercase`: accelerators are
+    // locale-defined and may be non-ASCII (e.g. German `ü`), where ASCII
+    // folding is a no-op and would leave an uppercase `Ü` unmatched.
+    let input = read_single_char_from_terminal()?
+        .to_lowercase()
+        .next()
+        .unwrap_or_default();
+
+    Ok(if input == locale.action("query-as-dependency").accelerator && supports_as_dependency {
+        ReviewIntention::AsDependency
+    } else if input == locale.action("query-delete").accelerator {
+        ReviewIntention::Delete
+    } else if input == locale.action("query-group").accelerator {
+        ReviewIntention::AssignGroup
+    } else if input == locale.action("query-info").accelerator {
+        ReviewIntention::Info
+    } else if input == locale.action("query-quit").accelerator {
+        ReviewIntention::Quit
+    } else if input == locale.action("query-skip").accelerator {
+        ReviewIntention::Skip
+    } else if input == locale.action("query-undo").accelerator {
+        ReviewIntention::Undo
+    } else {
+        ReviewIntention::Invalid
+    })
 }
 
 /// Print a space-terminated string that asks the user for the desired action.
 /// The items of the string depend on whether the backend supports dependent
-/// packages.
+/// packages, and their wording comes from `locale`.
 ///
 /// # Errors
 ///
 /// This function will return an error if stdout cannot be flushed.
-fn print_query(supports_as_dependency: bool) -> Result<()> {
-    let mut query = String::from("assign to (g)roup, (d)elete, (s)kip, (i)nfo, ");
+fn print_query(supports_as_dependency: bool, locale: &Locale) -> Result<()> {
+    let mut labels = vec![
+        locale.action("query-group").label,
+        locale.action("query-delete").label,
+        locale.action("query-skip").label,
+        locale.action("query-info").label,
+    ];
 
     if supports_as_dependency {
-        query.push_str("(a)s dependency, ");
+        labels.push(locale.action("query-as-dependency").label);
     }
 
-    query.push_str("(q)uit? ");
+    labels.push(locale.action("query-undo").label);
+    labels.push(locale.action("query-quit").label);
 
-    print!("{query}");
+    print!("{}? ", labels.join(", "));
     stdout().lock().flush()?;
     Ok(())
 }
@@ -156,26 +310,75 @@ fn print_enumerated_groups(groups: &[Rc<Group>]) {
     }
 }
 
+/// Like [`print_enumerated_groups`], but marks entry `0` as the highlighted,
+/// default match that a blank reply (just pressing Enter) confirms.
+fn print_narrowed_groups(groups: &[Rc<Group>]) {
+    let number_digits = get_amount_of_digits_for_number(groups.len());
+
+    for (i, group) in groups.iter().enumerate() {
+        let marker = if i == 0 { "*" } else { " " };
+        println!("{marker}{i:>number_digits$}: {}", group.name);
+    }
+}
+
 #[allow(clippy::as_conversions)] // this cannot introduce errors for any reasonably sized numbers.
 fn get_amount_of_digits_for_number(number: usize) -> usize {
     (number as f64).log10().trunc() as usize + 1
 }
 
+/// Ask the user to pick a [`Group`] by index or by name.
+///
+/// A numeric reply is still accepted as a plain index into `groups`, exactly
+/// as before. Anything else is treated as a fuzzy query: every group is
+/// scored with [`fuzzy_score`] against the typed text, non-matches are
+/// dropped, and the rest are narrowed down and re-presented by descending
+/// score. A single remaining match is confirmed immediately; otherwise the
+/// user picks by index from the narrowed list.
 fn ask_group(groups: &[Rc<Group>]) -> Result<Option<Rc<Group>>> {
     print_enumerated_groups(groups);
     let mut buf = String::new();
     stdin().read_line(&mut buf)?;
     let reply = buf.trim();
 
-    let idx: usize = if let Ok(idx) = reply.parse() {
-        idx
-    } else {
+    if let Ok(idx) = reply.parse::<usize>() {
+        return Ok(groups.get(idx).cloned());
+    }
+
+    if reply.is_empty() {
         return Ok(None);
-    };
+    }
 
-    if idx < groups.len() {
-        Ok(Some(groups[idx].clone()))
-    } else {
-        Ok(None)
+    let mut matches: Vec<(i64, &Rc<Group>)> = groups
+        .iter()
+        .filter_map(|group| fuzzy_score(reply, &group.name).map(|score| (score, group)))
+        .collect();
+
+    if matches.is_empty() {
+        return Ok(None);
     }
+
+    matches.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+    let narrowed: Vec<Rc<Group>> = matches.into_iter().map(|(_, group)| group.clone()).collect();
+
+    if let [only] = narrowed.as_slice() {
+        return Ok(Some(only.clone()));
+    }
+
+    print_narrowed_groups(&narrowed);
+    let mut buf = String::new();
+    stdin().read_line(&mut buf)?;
+    let reply = buf.trim();
+
+    // An empty reply confirms the highlighted (top-scored) match with Enter,
+    // as the fuzzy-matcher request asks for.
+    let idx: usize = if reply.is_empty() {
+        0
+    } else {
+        match reply.parse() {
+            Ok(idx) => idx,
+            Err(_) => return Ok(None),
+        }
+    };
+
+    Ok(narrowed.get(idx).cloned())
 }
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+
+/// What to do with a pending package when [`super::review`] is running
+/// non-interactively, e.g. in a script or CI where no TTY is attached.
+///
+/// Mirrors how `cargo add` takes every decision as an up-front flag rather
+/// than prompting: dump the unmanaged packages, edit a mapping file, and
+/// re-apply it with [`ReviewPolicy::parse_mapping`] for reproducible group
+/// maintenance.
+#[derive(Debug, Clone, Default)]
+pub enum ReviewPolicy {
+    /// Leave every pending package untouched. The default when no policy is
+    /// given, since it is the least destructive outcome.
+    #[default]
+    AssumeSkip,
+    /// Delete every pending package.
+    AssumeDelete,
+    /// Decide per package from a mapping of package name to [`MappingEntry`],
+    /// falling back to `default` for any package the mapping does not list.
+    Mapping {
+        assignments: HashMap<String, MappingEntry>,
+        default: Box<ReviewPolicy>,
+    },
+}
+
+impl ReviewPolicy {
+    /// Parse a mapping file of `package = group` / `package = delete` lines
+    /// into a [`ReviewPolicy::Mapping`], falling back to `default` for any
+    /// package the file doesn't list.
+    ///
+    /// Blank lines and lines starting with `#` are ignored. The value
+    /// `delete` (case-insensitive) maps the package to [`MappingEntry::Delete`];
+    /// anything else is taken as a group name and stored as-is — an unknown
+    /// or since-renamed group is not an error here, it is only reported when
+    /// the policy is resolved against the actual group list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a non-blank, non-comment line is not of the form
+    /// `key = value`.
+    pub fn parse_mapping(contents: &str, default: ReviewPolicy) -> Result<Self> {
+        let mut assignments = HashMap::new();
+
+        for (number, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (package, value) = line.split_once('=').with_context(|| {
+                format!(
+                    "line {}: expected `package = group` or `package = delete`, got {line:?}",
+                    number + 1
+                )
+            })?;
+
+            let package = package.trim().to_owned();
+            let value = value.trim();
+
+            let entry = if value.eq_ignore_ascii_case("delete") {
+                MappingEntry::Delete
+            } else {
+                MappingEntry::Group(value.to_owned())
+            };
+
+            assignments.insert(package, entry);
+        }
+
+        Ok(Self::Mapping {
+            assignments,
+            default: Box::new(default),
+        })
+    }
+}
+
+/// A single `package = group` or `package = delete` line in a review policy
+/// mapping file.
+#[derive(Debug, Clone)]
+pub enum MappingEntry {
+    Delete,
+    Group(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MappingEntry, ReviewPolicy};
+
+    fn assignments(policy: &ReviewPolicy) -> &std::collections::HashMap<String, MappingEntry> {
+        match policy {
+            ReviewPolicy::Mapping { assignments, .. } => assignments,
+            _ => panic!("expected ReviewPolicy::Mapping"),
+        }
+    }
+
+    #[test]
+    fn blank_lines_and_comments_are_ignored() {
+        let policy = ReviewPolicy::parse_mapping(
+            "\n# a comment\n  \nfoo = delete\n",
+            ReviewPolicy::AssumeSkip,
+        )
+        .unwrap();
+
+        assert_eq!(assignments(&policy).len(), 1);
+    }
+
+    #[test]
+    fn delete_keyword_is_case_insensitive() {
+        let policy =
+            ReviewPolicy::parse_mapping("foo = Delete\nbar = DELETE\n", ReviewPolicy::AssumeSkip)
+                .unwrap();
+
+        assert!(matches!(
+            assignments(&policy).get("foo"),
+            Some(MappingEntry::Delete)
+        ));
+        assert!(matches!(
+            assignments(&policy).get("bar"),
+            Some(MappingEntry::Delete)
+        ));
+    }
+
+    #[test]
+    fn unlisted_value_is_taken_as_a_group_name() {
+        let policy = ReviewPolicy::parse_mapping(
+            "firefox = browsers\nfoo = some-renamed-group\n",
+            ReviewPolicy::AssumeSkip,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            assignments(&policy).get("firefox"),
+            Some(MappingEntry::Group(name)) if name == "browsers"
+        ));
+        // A group name that doesn't (or no longer) exist is not a parse
+        // error: resolving it against the real group list happens later.
+        assert!(matches!(
+            assignments(&policy).get("foo"),
+            Some(MappingEntry::Group(name)) if name == "some-renamed-group"
+        ));
+    }
+
+    #[test]
+    fn line_without_equals_is_a_parse_error() {
+        let err = ReviewPolicy::parse_mapping("foo delete\n", ReviewPolicy::AssumeSkip).unwrap_err();
+        assert!(err.to_string().contains("line 1"));
+    }
+}
@@ -0,0 +1,98 @@
+use std::rc::Rc;
+
+use anyhow::Result;
+
+use crate::backend::Backend;
+use crate::{Group, Package};
+
+use super::ui::Progress;
+
+/// All decided actions for a single [`Backend`], ready to be applied.
+pub(super) struct Strategy {
+    backend: Box<dyn Backend>,
+    to_delete: Vec<Package>,
+    as_dependency: Vec<Package>,
+    assign_group: Vec<(Package, Rc<Group>)>,
+}
+
+impl Strategy {
+    pub(super) fn new(
+        backend: Box<dyn Backend>,
+        to_delete: Vec<Package>,
+        as_dependency: Vec<Package>,
+        assign_group: Vec<(Package, Rc<Group>)>,
+    ) -> Self {
+        Self {
+            backend,
+            to_delete,
+            as_dependency,
+            assign_group,
+        }
+    }
+
+    pub(super) fn nothing_to_do(&self) -> bool {
+        self.to_delete.is_empty() && self.as_dependency.is_empty() && self.assign_group.is_empty()
+    }
+
+    pub(super) fn show(&self) {
+        println!("{}:", self.backend.get_section());
+
+        if !self.to_delete.is_empty() {
+            println!("  delete:");
+            for package in &self.to_delete {
+                println!("    {package}");
+            }
+        }
+
+        if !self.as_dependency.is_empty() {
+            println!("  as dependency:");
+            for package in &self.as_dependency {
+                println!("    {package}");
+            }
+        }
+
+        if !self.assign_group.is_empty() {
+            println!("  assign group:");
+            for (package, group) in &self.assign_group {
+                println!("    {package} -> {}", group.name);
+            }
+        }
+    }
+
+    /// Apply every decided action for this backend.
+    ///
+    /// Progress is reported through a throttled [`Progress`] line after
+    /// each action category (delete / as-dependency / assign-group), so the
+    /// caller gets feedback while a backend is installing or removing many
+    /// packages without being spammed for small, fast strategies.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to apply an action.
+    pub(super) fn execute(&self) -> Result<()> {
+        let total = self.to_delete.len() + self.as_dependency.len() + self.assign_group.len();
+        let mut progress = Progress::new(self.backend.get_section(), total);
+        let mut done = 0;
+
+        if !self.to_delete.is_empty() {
+            self.backend.remove_packages(&self.to_delete)?;
+            done += self.to_delete.len();
+            progress.step(done);
+        }
+
+        if !self.as_dependency.is_empty() {
+            self.backend.make_dependency(&self.as_dependency)?;
+            done += self.as_dependency.len();
+            progress.step(done);
+        }
+
+        for (package, group) in &self.assign_group {
+            self.backend.assign_group(package, group)?;
+            done += 1;
+            progress.step(done);
+        }
+
+        progress.finish();
+        Ok(())
+    }
+}
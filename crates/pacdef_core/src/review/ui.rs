@@ -0,0 +1,58 @@
+use std::io::{stdout, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+/// How long a [`Strategy`](super::strategy::Strategy) execution must run
+/// before its progress line appears, so fast runs stay silent.
+const THRESHOLD: Duration = Duration::from_millis(500);
+
+/// Throttled progress reporter for a single strategy execution.
+///
+/// Borrows cargo's resolver-progress approach: a live status line is only
+/// emitted when stdout is a terminal, and only once [`THRESHOLD`] has
+/// elapsed since the strategy started. Each [`Progress::step`] call
+/// overwrites the previous line with the backend name and an `N of M`
+/// counter.
+pub(super) struct Progress {
+    label: String,
+    total: usize,
+    started_at: Instant,
+    shown: bool,
+    is_tty: bool,
+}
+
+impl Progress {
+    pub(super) fn new(label: impl Into<String>, total: usize) -> Self {
+        Self {
+            label: label.into(),
+            total,
+            started_at: Instant::now(),
+            shown: false,
+            is_tty: stdout().is_terminal(),
+        }
+    }
+
+    /// Record that `done` of `total` actions have been applied so far.
+    pub(super) fn step(&mut self, done: usize) {
+        if !self.is_tty {
+            return;
+        }
+
+        if !self.shown {
+            if self.started_at.elapsed() < THRESHOLD {
+                return;
+            }
+            self.shown = true;
+        }
+
+        print!("\r{}: {done} of {} actions", self.label, self.total);
+        let _ = stdout().flush();
+    }
+
+    /// End the status line with a newline, if one was ever shown. The last
+    /// `N of M` line stays on screen; it is not erased.
+    pub(super) fn finish(self) {
+        if self.shown {
+            println!();
+        }
+    }
+}